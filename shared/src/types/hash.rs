@@ -1,12 +1,14 @@
 //! Types for working with 32 bytes hashes.
 
-use std::fmt::{self, Display};
+use std::fmt::{self, Display, Write as _};
 use std::ops::Deref;
+use std::str::FromStr;
 
 use arse_merkle_tree::traits::Value;
 use arse_merkle_tree::{Hash as TreeHash, H256};
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use serde::{Deserialize, Serialize};
+use serde::de::Error as SerdeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
 
@@ -16,6 +18,8 @@ use crate::tendermint::Hash as TmHash;
 /// The length of the transaction hash string
 pub const HASH_LENGTH: usize = 32;
 
+const NULL_BYTES: [u8; HASH_LENGTH] = [0u8; HASH_LENGTH];
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum Error {
@@ -23,49 +27,194 @@ pub enum Error {
     Temporary { error: String },
     #[error("Failed trying to convert slice to a hash: {0}")]
     ConversionFailed(std::array::TryFromSliceError),
+    #[error(
+        "Invalid hex encoding length {0}, expected {}",
+        HASH_LENGTH * 2
+    )]
+    InvalidHexEncodingLength(usize),
     #[error("The string is not valid hex encoded data.")]
-    NotHexEncoded,
+    Invalid,
+    #[error("Unexpected hash length {actual}, expected {expected}")]
+    InvalidLength { expected: usize, actual: usize },
 }
 
 /// Result for functions that may fail
 pub type HashResult<T> = std::result::Result<T, Error>;
 
+/// The digest algorithm used to produce a [`Hash`].
+///
+/// Mirrors tendermint's `Algorithm`, which is kept alongside its `Hash` so
+/// that a hash value can be inspected without losing track of how it was
+/// produced.
 #[derive(
-    Clone,
-    Debug,
-    Default,
-    Hash,
-    PartialEq,
-    Eq,
-    BorshSerialize,
-    BorshDeserialize,
-    BorshSchema,
-    Serialize,
-    Deserialize,
+    Clone, Copy, Debug, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize, BorshSchema,
 )]
-/// A hash, typically a sha-2 hash of a tx
-pub struct Hash(pub [u8; 32]);
+pub enum HashAlgorithm {
+    /// SHA-256, namada's default digest.
+    Sha256,
+    /// Keccak-256, used by the Ethereum bridge and IBC light clients.
+    Keccak256,
+    /// Blake2b-256.
+    Blake2b256,
+}
 
-impl Display for Hash {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for byte in &self.0 {
-            write!(f, "{:02X}", byte)?;
+/// A hash, typically a sha-2 hash of a tx. Also supports other digest
+/// algorithms used by the merkle/storage and IBC layers, plus an explicit
+/// null value for "no hash".
+///
+/// `algorithm` is `None` exactly when this is the null hash, in which case
+/// `bytes` is all zeros.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Hash {
+    algorithm: Option<HashAlgorithm>,
+    bytes: [u8; HASH_LENGTH],
+}
+
+/// Borsh keeps encoding a SHA-256 `Hash` as the flat 32-byte digest it
+/// always was, with no algorithm tag, so on-chain/storage data written
+/// before the other algorithms existed (merkle tree nodes, stored tx
+/// hashes, wire messages) keeps decoding the same way. There is no room in
+/// that 32-byte encoding for an algorithm tag, so Borsh simply does not
+/// support encoding the other algorithms or the null hash; reach for the
+/// hex/binary serde impls below instead if you need those.
+impl BorshSerialize for Hash {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        match self.algorithm {
+            Some(HashAlgorithm::Sha256) => {
+                <[u8; HASH_LENGTH] as BorshSerialize>::serialize(
+                    &self.bytes,
+                    writer,
+                )
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "only a SHA-256 Hash can be borsh-serialized; its flat \
+                 32-byte encoding has no room for an algorithm tag",
+            )),
+        }
+    }
+}
+
+impl BorshDeserialize for Hash {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let bytes = <[u8; HASH_LENGTH] as BorshDeserialize>::deserialize(buf)?;
+        Ok(Self {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes,
+        })
+    }
+}
+
+impl BorshSchema for Hash {
+    fn declaration() -> borsh::schema::Declaration {
+        <[u8; HASH_LENGTH] as BorshSchema>::declaration()
+    }
+
+    fn add_definitions_recursively(
+        definitions: &mut std::collections::HashMap<
+            borsh::schema::Declaration,
+            borsh::schema::Definition,
+        >,
+    ) {
+        <[u8; HASH_LENGTH] as BorshSchema>::add_definitions_recursively(
+            definitions,
+        )
+    }
+}
+
+impl Default for Hash {
+    fn default() -> Self {
+        Self {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes: NULL_BYTES,
+        }
+    }
+}
+
+impl Hash {
+    /// The full, untruncated hex digits of this hash (empty for the null
+    /// hash), in the requested case.
+    fn hex_digits(&self, upper: bool) -> String {
+        if self.algorithm.is_none() {
+            return String::new();
+        }
+        let mut digits = String::with_capacity(self.bytes.len() * 2);
+        for byte in &self.bytes {
+            if upper {
+                let _ = write!(digits, "{:02X}", byte);
+            } else {
+                let _ = write!(digits, "{:02x}", byte);
+            }
+        }
+        digits
+    }
+
+    /// Shared implementation for [`Display`], [`fmt::LowerHex`] and
+    /// [`fmt::UpperHex`]: honors `f.precision()` (truncate to that many
+    /// hex characters), `f.width()`/`f.fill()`/alignment, and prepends
+    /// `0x` when `f.alternate()` is set.
+    fn fmt_hex(&self, f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+        let digits = self.hex_digits(upper);
+        let digits: String = match f.precision() {
+            Some(precision) => digits.chars().take(precision).collect(),
+            None => digits,
+        };
+        let prefix = if f.alternate() { "0x" } else { "" };
+
+        let body_len = prefix.len() + digits.chars().count();
+        let width = f.width().unwrap_or(body_len);
+        let pad = width.saturating_sub(body_len);
+        let fill = f.fill();
+        let (left_pad, right_pad) = match f.align().unwrap_or(fmt::Alignment::Left)
+        {
+            fmt::Alignment::Left => (0, pad),
+            fmt::Alignment::Right => (pad, 0),
+            fmt::Alignment::Center => (pad / 2, pad - pad / 2),
+        };
+
+        for _ in 0..left_pad {
+            write!(f, "{fill}")?;
+        }
+        write!(f, "{prefix}{digits}")?;
+        for _ in 0..right_pad {
+            write!(f, "{fill}")?;
         }
         Ok(())
     }
 }
 
+impl fmt::LowerHex for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, false)
+    }
+}
+
+impl fmt::UpperHex for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, true)
+    }
+}
+
+impl Display for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
 impl AsRef<[u8]> for Hash {
     fn as_ref(&self) -> &[u8] {
-        &self.0
+        self.as_bytes().as_slice()
     }
 }
 
 impl Deref for Hash {
-    type Target = [u8; 32];
+    type Target = [u8; HASH_LENGTH];
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        self.as_bytes()
     }
 }
 
@@ -82,15 +231,18 @@ impl TryFrom<&[u8]> for Hash {
                 ),
             });
         }
-        let hash: [u8; 32] =
+        let bytes: [u8; HASH_LENGTH] =
             TryFrom::try_from(value).map_err(Error::ConversionFailed)?;
-        Ok(Hash(hash))
+        Ok(Hash {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes,
+        })
     }
 }
 
 impl From<Hash> for transaction::Hash {
     fn from(hash: Hash) -> Self {
-        Self::new(hash.0)
+        Self::new(*hash.as_bytes())
     }
 }
 
@@ -98,56 +250,391 @@ impl Hash {
     /// Compute sha256 of some bytes
     pub fn sha256(data: impl AsRef<[u8]>) -> Self {
         let digest = Sha256::digest(data.as_ref());
-        Self(*digest.as_ref())
+        Self {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes: *digest.as_ref(),
+        }
+    }
+
+    /// Compute `sha256(sha256(data))` in one call, as used by
+    /// Bitcoin-style commitments for bridge/IBC interop.
+    pub fn sha256d(data: impl AsRef<[u8]>) -> Self {
+        Self::sha256(Self::sha256(data).as_bytes())
+    }
+
+    /// Build a hash of the given algorithm from its raw digest bytes,
+    /// checking that the byte length matches what the algorithm produces.
+    pub fn new(
+        algorithm: HashAlgorithm,
+        bytes: impl AsRef<[u8]>,
+    ) -> HashResult<Self> {
+        let bytes = bytes.as_ref();
+        if bytes.len() != HASH_LENGTH {
+            return Err(Error::InvalidLength {
+                expected: HASH_LENGTH,
+                actual: bytes.len(),
+            });
+        }
+        let mut buf = [0u8; HASH_LENGTH];
+        buf.copy_from_slice(bytes);
+        Ok(Self {
+            algorithm: Some(algorithm),
+            bytes: buf,
+        })
+    }
+
+    /// The explicit null hash, i.e. "no hash". `Display`s as an empty
+    /// string and round-trips through `from_hex`/`FromStr`.
+    pub fn null() -> Self {
+        Self {
+            algorithm: None,
+            bytes: NULL_BYTES,
+        }
     }
 
-    /// Check if the hash is all zeros
+    /// The algorithm that produced this hash, or `None` for the null hash.
+    pub fn algorithm(&self) -> Option<HashAlgorithm> {
+        self.algorithm
+    }
+
+    /// The raw digest bytes backing this hash. The null hash is
+    /// represented as all zeros.
+    pub fn as_bytes(&self) -> &[u8; HASH_LENGTH] {
+        &self.bytes
+    }
+
+    /// Check if the hash is all zeros, i.e. the null hash or an all-zero
+    /// digest.
     pub fn is_zero(&self) -> bool {
-        self == &Self::zero()
+        self.algorithm.is_none() || self.bytes.iter().all(|&byte| byte == 0)
+    }
+
+    /// Decode a hash from its 64 character hex representation, accepting
+    /// both upper- and lowercase digits. An empty string decodes to the
+    /// null hash.
+    pub fn from_hex(str: impl AsRef<str>) -> HashResult<Self> {
+        let str = str.as_ref();
+        if str.is_empty() {
+            return Ok(Self::null());
+        }
+        if str.len() != HASH_LENGTH * 2 {
+            return Err(Error::InvalidHexEncodingLength(str.len()));
+        }
+        let mut bytes = [0u8; HASH_LENGTH];
+        for (byte, pair) in bytes.iter_mut().zip(str.as_bytes().chunks_exact(2))
+        {
+            *byte = (hex_nibble(pair[0])? << 4) | hex_nibble(pair[1])?;
+        }
+        Ok(Self {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes,
+        })
+    }
+}
+
+/// A streaming SHA-256 hasher that implements [`std::io::Write`], so large
+/// structures (e.g. a `borsh`-serialized transaction) can be hashed
+/// incrementally, by feeding it straight to `BorshSerialize::serialize`,
+/// instead of first materializing a full byte buffer to pass to
+/// [`Hash::sha256`].
+pub struct HashWriter(Sha256);
+
+impl HashWriter {
+    /// Start a new streaming hash.
+    pub fn new() -> Self {
+        Self(Sha256::new())
+    }
+
+    /// Finalize the streamed data into a [`Hash`].
+    pub fn finalize(self) -> Hash {
+        Hash {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes: self.0.finalize().into(),
+        }
+    }
+}
+
+impl Default for HashWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::io::Write for HashWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl FromStr for Hash {
+    type Err = Error;
+
+    fn from_str(str: &str) -> HashResult<Self> {
+        Self::from_hex(str)
+    }
+}
+
+/// A tag identifying which variant a binary-encoded `Hash` holds, since
+/// unlike Borsh, serde's binary formats need to round-trip every
+/// algorithm (and the null hash) losslessly.
+const SERDE_TAG_SHA256: u8 = 0;
+const SERDE_TAG_KECCAK256: u8 = 1;
+const SERDE_TAG_BLAKE2B256: u8 = 2;
+const SERDE_TAG_NULL: u8 = 3;
+
+/// Serializes as a lowercase hex string for human-readable formats (JSON,
+/// TOML, ...), matching how hashes already appear everywhere else (e.g. in
+/// RPC responses), the same way tendermint's hash type serializes via a
+/// hex encoder. Binary formats prepend a 1-byte algorithm tag to the
+/// 32-byte digest, so every variant (and the null hash) round-trips
+/// losslessly.
+impl Serialize for Hash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string().to_lowercase())
+        } else {
+            let tag = match self.algorithm {
+                Some(HashAlgorithm::Sha256) => SERDE_TAG_SHA256,
+                Some(HashAlgorithm::Keccak256) => SERDE_TAG_KECCAK256,
+                Some(HashAlgorithm::Blake2b256) => SERDE_TAG_BLAKE2B256,
+                None => SERDE_TAG_NULL,
+            };
+            <(u8, [u8; HASH_LENGTH]) as Serialize>::serialize(
+                &(tag, self.bytes),
+                serializer,
+            )
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Hash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let hex = <String as Deserialize>::deserialize(deserializer)?;
+            Self::from_hex(hex).map_err(SerdeError::custom)
+        } else {
+            let (tag, bytes) =
+                <(u8, [u8; HASH_LENGTH]) as Deserialize>::deserialize(
+                    deserializer,
+                )?;
+            let algorithm = match tag {
+                SERDE_TAG_SHA256 => Some(HashAlgorithm::Sha256),
+                SERDE_TAG_KECCAK256 => Some(HashAlgorithm::Keccak256),
+                SERDE_TAG_BLAKE2B256 => Some(HashAlgorithm::Blake2b256),
+                SERDE_TAG_NULL if bytes == NULL_BYTES => None,
+                SERDE_TAG_NULL => {
+                    return Err(SerdeError::custom(
+                        "the null Hash must have all-zero bytes",
+                    ))
+                }
+                other => {
+                    return Err(SerdeError::custom(format!(
+                        "invalid Hash algorithm tag {other}"
+                    )))
+                }
+            };
+            Ok(Self { algorithm, bytes })
+        }
+    }
+}
+
+/// Decode a single hex digit, accepting both upper- and lowercase.
+fn hex_nibble(ch: u8) -> HashResult<u8> {
+    match ch {
+        b'0'..=b'9' => Ok(ch - b'0'),
+        b'a'..=b'f' => Ok(ch - b'a' + 10),
+        b'A'..=b'F' => Ok(ch - b'A' + 10),
+        _ => Err(Error::Invalid),
     }
 }
 
 impl From<Hash> for TmHash {
     fn from(hash: Hash) -> Self {
-        TmHash::Sha256(hash.0)
+        TmHash::Sha256(*hash.as_bytes())
     }
 }
 
 impl From<H256> for Hash {
     fn from(hash: H256) -> Self {
-        Hash(hash.into())
+        Hash {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes: hash.into(),
+        }
     }
 }
 
 impl From<&H256> for Hash {
     fn from(hash: &H256) -> Self {
         let hash = *hash;
-        Hash(hash.into())
+        Hash {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes: hash.into(),
+        }
     }
 }
 
 impl From<Hash> for H256 {
     fn from(hash: Hash) -> H256 {
-        hash.0.into()
+        (*hash.as_bytes()).into()
     }
 }
 
 impl From<Hash> for TreeHash {
     fn from(hash: Hash) -> Self {
-        Self::from(hash.0)
+        Self::from(*hash.as_bytes())
     }
 }
 
 impl Value for Hash {
     fn as_slice(&self) -> &[u8] {
-        self.0.as_slice()
+        self.as_bytes().as_slice()
     }
 
     fn zero() -> Self {
-        Hash([0u8; 32])
+        Hash {
+            algorithm: Some(HashAlgorithm::Sha256),
+            bytes: NULL_BYTES,
+        }
     }
 }
 
+impl Hash {
+    /// Compute the merkle root of a flat list of leaf hashes, independent
+    /// of the sparse merkle tree above. Combines adjacent pairs
+    /// bottom-up as `sha256(left ++ right)`, duplicating the last node of
+    /// an odd-sized level so it pairs with itself. An empty input is
+    /// `Hash::zero()` and a single leaf is returned unchanged.
+    pub fn merkle_root(leaves: &[Hash]) -> Hash {
+        match leaves {
+            [] => <Hash as Value>::zero(),
+            [leaf] => leaf.clone(),
+            _ => {
+                let mut level = leaves.to_vec();
+                while level.len() > 1 {
+                    level = merkle_parent_level(&level);
+                }
+                level.remove(0)
+            }
+        }
+    }
+}
+
+/// Combine two sibling hashes into their parent: `sha256(left ++ right)`.
+fn merkle_parent(left: &Hash, right: &Hash) -> Hash {
+    let mut buf = Vec::with_capacity(HASH_LENGTH * 2);
+    buf.extend_from_slice(left.as_bytes());
+    buf.extend_from_slice(right.as_bytes());
+    Hash::sha256(buf)
+}
+
+/// Pair up a level of the tree into its parent level, duplicating the
+/// last node if the level has an odd number of nodes.
+fn merkle_parent_level(level: &[Hash]) -> Vec<Hash> {
+    level
+        .chunks(2)
+        .map(|pair| {
+            let left = &pair[0];
+            let right = pair.get(1).unwrap_or(left);
+            merkle_parent(left, right)
+        })
+        .collect()
+}
+
+/// One step of a merkle inclusion proof: a sibling hash, and whether it
+/// sits to the left of the node being proven at that level.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProofStep {
+    /// The sibling hash at this level.
+    pub sibling: Hash,
+    /// Whether `sibling` is the left-hand node of the pair.
+    pub sibling_is_left: bool,
+}
+
+/// An ordered list of sibling hashes from leaf to root.
+pub type MerkleProof = Vec<MerkleProofStep>;
+
+/// A merkle tree builder that retains every intermediate level, so that
+/// inclusion proofs can be extracted for any leaf after the fact. All
+/// leaves are given at once to [`MerkleTree::new`]; leaves cannot be
+/// added to a tree once it is built.
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    /// Each level of the tree, from the leaves (`levels[0]`) up to a
+    /// single-element root level (`levels[levels.len() - 1]`).
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    /// Build a merkle tree over the given leaves.
+    pub fn new(leaves: Vec<Hash>) -> Self {
+        if leaves.is_empty() {
+            return Self {
+                levels: vec![vec![<Hash as Value>::zero()]],
+            };
+        }
+
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let parent_level = merkle_parent_level(levels.last().unwrap());
+            levels.push(parent_level);
+        }
+        Self { levels }
+    }
+
+    /// The merkle root.
+    pub fn root(&self) -> Hash {
+        self.levels.last().unwrap()[0].clone()
+    }
+
+    /// Build the inclusion proof for the leaf at `index`, or `None` if
+    /// `index` is out of bounds.
+    pub fn proof(&self, mut index: usize) -> Option<MerkleProof> {
+        if index >= self.levels[0].len() {
+            return None;
+        }
+
+        let mut proof = Vec::with_capacity(self.levels.len() - 1);
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling_is_left = index % 2 == 1;
+            let sibling_index = if sibling_is_left { index - 1 } else { index + 1 };
+            let sibling = level
+                .get(sibling_index)
+                .unwrap_or(&level[index])
+                .clone();
+            proof.push(MerkleProofStep {
+                sibling,
+                sibling_is_left,
+            });
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+/// Verify that `leaf` is included under `root`, via an inclusion `proof`
+/// as produced by [`MerkleTree::proof`].
+pub fn verify_merkle_proof(leaf: &Hash, proof: &MerkleProof, root: &Hash) -> bool {
+    let computed = proof.iter().fold(leaf.clone(), |node, step| {
+        if step.sibling_is_left {
+            merkle_parent(&step.sibling, &node)
+        } else {
+            merkle_parent(&node, &step.sibling)
+        }
+    });
+    &computed == root
+}
+
 /// A hex encoded hash.
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct HashString {
@@ -187,22 +674,28 @@ impl TryFrom<&str> for HashString {
     fn try_from(hash: &str) -> HashResult<Self> {
         const HEX_LEN: usize = HASH_LENGTH * 2;
 
-        let mut hash_len = 0;
-        let mut buf = [0; HEX_LEN];
+        if hash.len() != HEX_LEN {
+            return Err(self::Error::InvalidHexEncodingLength(hash.len()));
+        }
 
-        for (slot, ch) in buf.iter_mut().zip(hash.chars().take(HEX_LEN)) {
+        let mut buf = [0; HEX_LEN];
+        for (slot, ch) in buf.iter_mut().zip(hash.chars()) {
             match ch {
                 'a'..='f' | 'A'..='F' | '0'..='9' => *slot = ch as u8,
-                _ => return Err(self::Error::NotHexEncoded),
+                _ => return Err(self::Error::Invalid),
             }
-            hash_len += 1;
         }
 
-        if hash_len == HEX_LEN {
-            Ok(HashString { inner: buf })
-        } else {
-            Err(self::Error::NotHexEncoded)
-        }
+        Ok(HashString { inner: buf })
+    }
+}
+
+impl HashString {
+    /// Decode this hex encoded hash back into a [`Hash`], reusing the
+    /// already-validated buffer.
+    pub fn decode(&self) -> Hash {
+        Hash::from_hex(self.deref())
+            .expect("HashString always contains valid hex")
     }
 }
 
@@ -223,5 +716,157 @@ mod tests {
         fn test_hash_string(hex_hash in hex_encoded_hash_strat()) {
             let _: HashString = hex_hash.try_into().unwrap();
         }
+
+        #[test]
+        fn test_hash_roundtrips_through_display_and_from_hex(
+            raw_hash in proptest::array::uniform32(any::<u8>())
+        ) {
+            let hash = Hash::new(HashAlgorithm::Sha256, raw_hash).unwrap();
+            let decoded: Hash = hash.to_string().parse().unwrap();
+            assert_eq!(hash, decoded);
+        }
+    }
+
+    #[test]
+    fn test_from_hex_distinguishes_bad_length_from_bad_char() {
+        assert!(matches!(
+            Hash::from_hex("abcd"),
+            Err(Error::InvalidHexEncodingLength(4))
+        ));
+
+        let mut bad_char = "a".repeat(HASH_LENGTH * 2);
+        bad_char.replace_range(0..1, "z");
+        assert!(matches!(Hash::from_hex(bad_char), Err(Error::Invalid)));
+    }
+
+    #[test]
+    fn test_from_hex_accepts_uppercase() {
+        let lower = Hash::from_hex("ab".repeat(HASH_LENGTH)).unwrap();
+        let upper = Hash::from_hex("AB".repeat(HASH_LENGTH)).unwrap();
+        assert_eq!(lower, upper);
+    }
+
+    #[test]
+    fn test_null_hash_roundtrips() {
+        assert_eq!(Hash::null().to_string(), "");
+        assert_eq!(Hash::from_hex("").unwrap(), Hash::null());
+        assert!(Hash::null().is_zero());
+        assert_eq!(Hash::null().algorithm(), None);
+    }
+
+    #[test]
+    fn test_hash_new_validates_length_and_tracks_algorithm() {
+        assert!(matches!(
+            Hash::new(HashAlgorithm::Keccak256, [0u8; HASH_LENGTH - 1]),
+            Err(Error::InvalidLength {
+                expected: HASH_LENGTH,
+                actual,
+            }) if actual == HASH_LENGTH - 1
+        ));
+
+        let keccak = Hash::new(HashAlgorithm::Keccak256, [7u8; HASH_LENGTH]).unwrap();
+        assert_eq!(keccak.algorithm(), Some(HashAlgorithm::Keccak256));
+        assert_eq!(keccak.as_bytes(), &[7u8; HASH_LENGTH]);
+
+        let blake2b =
+            Hash::new(HashAlgorithm::Blake2b256, [9u8; HASH_LENGTH]).unwrap();
+        assert_eq!(blake2b.algorithm(), Some(HashAlgorithm::Blake2b256));
+        assert_ne!(keccak, blake2b);
+    }
+
+    #[test]
+    fn test_hash_binary_serde_roundtrips_every_variant() {
+        let hashes = [
+            Hash::new(HashAlgorithm::Sha256, [1u8; HASH_LENGTH]).unwrap(),
+            Hash::new(HashAlgorithm::Keccak256, [2u8; HASH_LENGTH]).unwrap(),
+            Hash::new(HashAlgorithm::Blake2b256, [3u8; HASH_LENGTH]).unwrap(),
+            Hash::null(),
+        ];
+        for hash in hashes {
+            let encoded = bincode::serialize(&hash).unwrap();
+            let decoded: Hash = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(decoded, hash);
+            assert_eq!(decoded.algorithm(), hash.algorithm());
+        }
+    }
+
+    #[test]
+    fn test_merkle_root_edge_cases() {
+        assert_eq!(Hash::merkle_root(&[]), <Hash as Value>::zero());
+
+        let leaf = Hash::sha256("a single leaf");
+        assert_eq!(Hash::merkle_root(std::slice::from_ref(&leaf)), leaf);
+    }
+
+    #[test]
+    fn test_hash_format_roundtrips_through_full_display() {
+        let hash = Hash::sha256("round trip me");
+        let rendered = format!("{hash}");
+        assert_eq!(rendered.len(), HASH_LENGTH * 2);
+        assert_eq!(rendered.parse::<Hash>().unwrap(), hash);
+    }
+
+    #[test]
+    fn test_hash_format_honors_precision_width_and_alternate() {
+        let hash = Hash::sha256("log line prefix");
+        let full = format!("{:x}", hash);
+
+        // precision truncates to that many hex characters
+        assert_eq!(format!("{:.8}", hash), full[..8]);
+
+        // width pads with the given fill, left-aligned by default
+        assert_eq!(format!("{:<12.8}", hash), format!("{}    ", &full[..8]));
+        assert_eq!(format!("{:>12.8}", hash), format!("    {}", &full[..8]));
+        assert_eq!(format!("{:*^12.8}", hash), format!("**{}**", &full[..8]));
+
+        // alternate form prepends 0x
+        assert_eq!(format!("{:#x}", hash), format!("0x{full}"));
+        assert_eq!(format!("{:#.8x}", hash), format!("0x{}", &full[..8]));
+
+        // uppercase variant
+        assert_eq!(format!("{:X}", hash), full.to_uppercase());
+    }
+
+    #[test]
+    fn test_sha256d_is_double_sha256() {
+        let data = b"some transaction bytes";
+
+        // Computed independently of `Hash::sha256d`/`Hash::sha256`, straight
+        // off `sha2::Sha256`, so this doesn't just restate `sha256d`'s body.
+        let once = Sha256::digest(data);
+        let twice = Sha256::digest(once);
+
+        assert_eq!(Hash::sha256d(data).as_bytes(), twice.as_slice());
+    }
+
+    #[test]
+    fn test_hash_writer_matches_sha256() {
+        use std::io::Write;
+
+        let data = b"a large structure fed in chunks";
+        let mut writer = HashWriter::new();
+        writer.write_all(&data[..10]).unwrap();
+        writer.write_all(&data[10..]).unwrap();
+
+        assert_eq!(writer.finalize(), Hash::sha256(data));
+    }
+
+    #[test]
+    fn test_merkle_tree_proof_verifies_against_root() {
+        let leaves: Vec<Hash> = (0..5)
+            .map(|i| Hash::sha256(format!("leaf-{i}")))
+            .collect();
+        let tree = MerkleTree::new(leaves.clone());
+        let root = tree.root();
+        assert_eq!(root, Hash::merkle_root(&leaves));
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = tree.proof(i).unwrap();
+            assert!(verify_merkle_proof(leaf, &proof, &root));
+        }
+
+        let bogus_leaf = Hash::sha256("not in the tree");
+        let proof = tree.proof(0).unwrap();
+        assert!(!verify_merkle_proof(&bogus_leaf, &proof, &root));
     }
 }